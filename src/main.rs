@@ -1,4 +1,6 @@
+use nannou::glam::{Quat, Vec3};
 use nannou::prelude::*;
+use nannou::winit::event::{ElementState, MouseButton, WindowEvent};
 use std::sync::Arc;
 
 use nannou_egui::{self, egui, Egui};
@@ -17,36 +19,161 @@ const GRID_COUNT: usize = 10;
 const GRID_PAD: f32 = 0.5 / (GRID_COUNT as f32);
 const GRID_SIZE: f32 = ((GRID_COUNT - 1) as f32) * GRID_PAD;
 const CIRCLE_RADIUS: f32 = 5.0;
+const NEAR_PLANE: f32 = 0.05;
+
+#[derive(Clone, Copy, PartialEq)]
+enum ColorSpace {
+    Rgb,
+    Hsv,
+    Hsl,
+    OkLab,
+}
+
+impl ColorSpace {
+    const ALL: [ColorSpace; 4] = [
+        ColorSpace::Rgb,
+        ColorSpace::Hsv,
+        ColorSpace::Hsl,
+        ColorSpace::OkLab,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            ColorSpace::Rgb => "RGB",
+            ColorSpace::Hsv => "HSV",
+            ColorSpace::Hsl => "HSL",
+            ColorSpace::OkLab => "OKLab",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum RenderMode {
+    Lattice,
+    Mandelbulb,
+}
+
+impl RenderMode {
+    const ALL: [RenderMode; 2] = [RenderMode::Lattice, RenderMode::Mandelbulb];
+
+    fn label(&self) -> &'static str {
+        match self {
+            RenderMode::Lattice => "Lattice",
+            RenderMode::Mandelbulb => "Mandelbulb",
+        }
+    }
+}
 
 struct Model {
-    angle_x: f32,
-    angle_y: f32,
+    orientation: Quat,
+    drag_start: Option<Vec3>,
     ui: Egui,
     z_start: f32,
     rot_speed_x: f32,
     rot_speed_y: f32,
+    focal_length: f32,
+    fog_near: f32,
+    fog_far: f32,
+    color_space: ColorSpace,
+    render_mode: RenderMode,
+    mandelbulb_power: f32,
+    mandelbulb_eps: f32,
+    mandelbulb_iterations: u32,
+    trail: bool,
+    trail_decay: f32,
+    frame_count: u64,
+    recording: bool,
+    was_recording: bool,
+    recording_run: u64,
+    recording_frame: u64,
+    headless: bool,
 }
 
 #[derive(Clone)]
 struct ViewData {
-    angle_x: f32,
-    angle_y: f32,
+    orientation: Quat,
     z_start: f32,
     colors: Arc<Vec<Srgba>>,
+    render_mode: RenderMode,
+    mandelbulb_power: f32,
+    mandelbulb_eps: f32,
+    mandelbulb_iterations: u32,
+}
+
+/// Projects normalized window coordinates `(x, y)` onto a virtual trackball: the unit
+/// sphere inside the unit disk, and a hyperbolic sheet beyond it so drags that leave the
+/// disk keep producing a sensible rotation instead of clamping at the equator.
+fn arcball_point(x: f32, y: f32) -> Vec3 {
+    let r2 = x * x + y * y;
+    let p = if r2 <= 1.0 {
+        Vec3::new(x, y, (1.0 - r2).sqrt())
+    } else {
+        let r = r2.sqrt();
+        Vec3::new(x, y, 1.0 / (2.0 * r))
+    };
+    p.normalize()
+}
+
+/// The quaternion that rotates `from` onto `to`, both assumed to be on the unit sphere.
+fn arcball_quat(from: Vec3, to: Vec3) -> Quat {
+    let axis = from.cross(to);
+    let angle = from.dot(to).clamp(-1.0, 1.0).acos();
+    if axis.length_squared() < 1e-6 || angle.abs() < 1e-6 {
+        Quat::IDENTITY
+    } else {
+        Quat::from_axis_angle(axis.normalize(), angle)
+    }
+}
+
+fn lerp_f32(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Linearly interpolates each channel of `color` toward `target` by `t` (0 = `color`, 1 = `target`).
+fn lerp_color(color: Srgba, target: Srgba, t: f32) -> Srgba {
+    let t = t.clamp(0.0, 1.0);
+    srgba(
+        lerp_f32(color.red, target.red, t),
+        lerp_f32(color.green, target.green, t),
+        lerp_f32(color.blue, target.blue, t),
+        color.alpha,
+    )
+}
+
+/// Parses `--frames N` from the CLI, used to bound a `--headless` run to a deterministic
+/// number of frames instead of running until a window is closed.
+fn headless_frame_count() -> usize {
+    let args: Vec<String> = std::env::args().collect();
+    args.iter()
+        .position(|a| a == "--frames")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(300)
 }
 
 fn main() {
-    nannou::app(model)
-        .update(update)
-        .loop_mode(LoopMode::refresh_sync())
-        .run();
+    let headless = std::env::args().any(|a| a == "--headless");
+
+    let mut app = nannou::app(model).update(update);
+
+    app = if headless {
+        app.loop_mode(LoopMode::loop_ntimes(headless_frame_count()))
+    } else {
+        app.loop_mode(LoopMode::refresh_sync())
+    };
+
+    app.run();
 }
 
 fn model(app: &App) -> Model {
+    let headless = std::env::args().any(|a| a == "--headless");
+
     let _window = app
         .new_window()
         .size(WIDTH, HEIGHT)
         .view(view)
+        .raw_event(raw_main_event)
+        .visible(!headless)
         .build()
         .unwrap();
 
@@ -55,6 +182,7 @@ fn model(app: &App) -> Model {
         .size(300, 200)
         .view(ui_view)
         .raw_event(raw_ui_event)
+        .visible(!headless)
         .build()
         .unwrap();
 
@@ -62,32 +190,167 @@ fn model(app: &App) -> Model {
     let ui = Egui::from_window(&ui_window_ref);
 
     Model {
-        angle_x: 0.0,
-        angle_y: 0.0,
+        orientation: Quat::IDENTITY,
+        drag_start: None,
         ui,
         z_start: 0.4,
         rot_speed_x: 0.25 * PI,
         rot_speed_y: 0.25 * PI,
+        focal_length: 1.0,
+        fog_near: 0.4,
+        fog_far: 1.4,
+        color_space: ColorSpace::Rgb,
+        render_mode: RenderMode::Lattice,
+        mandelbulb_power: 8.0,
+        mandelbulb_eps: 0.01,
+        mandelbulb_iterations: 16,
+        trail: false,
+        trail_decay: 0.05,
+        frame_count: 0,
+        recording: headless,
+        was_recording: false,
+        recording_run: 0,
+        recording_frame: 0,
+        headless,
     }
 }
 
+const HEADLESS_DT: f32 = 1.0 / 30.0;
+
 fn update(_app: &App, model: &mut Model, update: Update) {
     update_ui(model);
-    model.angle_x += model.rot_speed_x * update.since_last.as_secs_f32();
-    model.angle_y += model.rot_speed_y * update.since_last.as_secs_f32();
+
+    // A fixed timestep keeps headless renders frame-rate independent and reproducible.
+    let dt = if model.headless {
+        HEADLESS_DT
+    } else {
+        update.since_last.as_secs_f32()
+    };
+
+    let spin = Quat::from_rotation_x(model.rot_speed_x * dt)
+        * Quat::from_rotation_y(model.rot_speed_y * dt);
+    model.orientation = (spin * model.orientation).normalize();
+    model.frame_count += 1;
+
+    if model.recording {
+        if !model.was_recording {
+            // A fresh recording run: give it its own frame namespace so runs with
+            // different parameters (or separated by a pause) never share frame numbers.
+            model.recording_run += 1;
+            model.recording_frame = 0;
+            write_recording_sidecar(model);
+        } else {
+            model.recording_frame += 1;
+        }
+    }
+    model.was_recording = model.recording;
+}
+
+/// Directory a recording run's frames and sidecar are written to.
+fn recording_dir(run: u64) -> String {
+    format!("frames/run_{:04}", run)
+}
+
+/// Writes the parameters for the current recording run to `<run_dir>/run_meta.json` so the
+/// resulting PNG sequence can be reproduced later.
+fn write_recording_sidecar(model: &Model) {
+    let dir = recording_dir(model.recording_run);
+    let _ = std::fs::create_dir_all(&dir);
+    let json = format!(
+        "{{\n  \"z_start\": {},\n  \"rot_speed_x\": {},\n  \"rot_speed_y\": {},\n  \"focal_length\": {},\n  \"fog_near\": {},\n  \"fog_far\": {},\n  \"color_space\": \"{}\",\n  \"render_mode\": \"{}\",\n  \"mandelbulb_power\": {},\n  \"mandelbulb_eps\": {},\n  \"mandelbulb_iterations\": {},\n  \"trail\": {},\n  \"trail_decay\": {}\n}}\n",
+        model.z_start,
+        model.rot_speed_x,
+        model.rot_speed_y,
+        model.focal_length,
+        model.fog_near,
+        model.fog_far,
+        model.color_space.label(),
+        model.render_mode.label(),
+        model.mandelbulb_power,
+        model.mandelbulb_eps,
+        model.mandelbulb_iterations,
+        model.trail,
+        model.trail_decay,
+    );
+    let _ = std::fs::write(format!("{}/run_meta.json", dir), json);
+}
+
+/// Converts a hue/chroma/second-chroma-axis triple shared by HSV and HSL into an
+/// `(r, g, b)` sextant pick, per the standard sextant formula.
+fn hue_sextant(h: f32, c: f32) -> (f32, f32, f32) {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = c * (1.0 - ((h % 2.0) - 1.0).abs());
+    match h.floor() as i32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    }
+}
+
+fn hsv_to_rgb(h: f32, s: f32, v: f32) -> (f32, f32, f32) {
+    let c = v * s;
+    let (r, g, b) = hue_sextant(h, c);
+    let m = v - c;
+    (r + m, g + m, b + m)
 }
 
-fn generate_colors(grid_count: usize) -> Vec<Srgba> {
+fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let (r, g, b) = hue_sextant(h, c);
+    let m = l - c / 2.0;
+    (r + m, g + m, b + m)
+}
+
+fn srgb_gamma(x: f32) -> f32 {
+    let x = x.clamp(0.0, 1.0);
+    if x <= 0.0031308 {
+        12.92 * x
+    } else {
+        1.055 * x.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Converts OKLab coordinates to gamma-encoded sRGB, per Björn Ottosson's reference matrices.
+// The matrix constants below are transcribed verbatim from the reference; truncating them
+// would just make them harder to cross-check against the source.
+#[allow(clippy::excessive_precision)]
+fn oklab_to_rgb(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
+    let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
+    let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
+
+    let l_ = l_ * l_ * l_;
+    let m_ = m_ * m_ * m_;
+    let s_ = s_ * s_ * s_;
+
+    let r = 4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_;
+    let g = -1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_;
+    let b = -0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_;
+
+    (srgb_gamma(r), srgb_gamma(g), srgb_gamma(b))
+}
+
+fn generate_colors(grid_count: usize, color_space: ColorSpace) -> Vec<Srgba> {
     let mut colors = Vec::with_capacity(grid_count * grid_count * grid_count);
 
     for ix in 0..grid_count {
         for iy in 0..grid_count {
             for iz in 0..grid_count {
-                let r = (ix * 255) / grid_count;
-                let g = (iy * 255) / grid_count;
-                let b = (iz * 255) / grid_count;
-                let color = srgba(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, 1.0);
-                colors.push(color);
+                let u = ix as f32 / grid_count as f32;
+                let v = iy as f32 / grid_count as f32;
+                let w = iz as f32 / grid_count as f32;
+
+                let (r, g, b) = match color_space {
+                    ColorSpace::Rgb => (u, v, w),
+                    ColorSpace::Hsv => hsv_to_rgb(u * 360.0, v, w),
+                    ColorSpace::Hsl => hsl_to_rgb(u * 360.0, v, w),
+                    ColorSpace::OkLab => oklab_to_rgb(u, (v - 0.5) * 0.8, (w - 0.5) * 0.8),
+                };
+
+                colors.push(srgba(r, g, b, 1.0));
             }
         }
     }
@@ -95,25 +358,78 @@ fn generate_colors(grid_count: usize) -> Vec<Srgba> {
     colors
 }
 
+/// Maps a grid index into `[-1.25, 1.25]`, the cube the Mandelbulb is sampled over.
+fn fractal_coord(i: usize, grid_count: usize) -> f32 {
+    let t = i as f32 / (grid_count - 1) as f32;
+    -1.25 + t * 2.5
+}
+
+/// Mandelbulb distance estimator at `p`. Returns `(de, iterations)`, the signed distance
+/// estimate and the number of iterations survived before escaping (or running out).
+fn mandelbulb_de(p: Vec3, power: f32, max_iterations: u32) -> (f32, u32) {
+    let mut z = p;
+    let mut dr = 1.0;
+    let mut r = 0.0;
+    let mut iterations = 0;
+
+    for i in 0..max_iterations {
+        r = z.length();
+        if r > 2.0 {
+            break;
+        }
+        if r == 0.0 {
+            // `p` landed exactly on the origin: theta/phi are undefined there, and `z`
+            // stays at the origin on every later iteration anyway, so just stop early.
+            break;
+        }
+
+        let theta = (z.z / r).acos();
+        let phi = z.y.atan2(z.x);
+        dr = r.powf(power - 1.0) * power * dr + 1.0;
+
+        let zr = r.powf(power);
+        let theta = theta * power;
+        let phi = phi * power;
+
+        z = zr * Vec3::new(theta.sin() * phi.cos(), theta.sin() * phi.sin(), theta.cos()) + p;
+        iterations = i + 1;
+    }
+
+    (0.5 * r.ln() * r / dr, iterations)
+}
+
 fn view(app: &App, model: &Model, frame: Frame) {
     let draw = app.draw();
 
-    draw.background().color(srgba(
-        BACKGROUND_COLOR_R,
-        BACKGROUND_COLOR_G,
-        BACKGROUND_COLOR_B,
-        BACKGROUND_COLOR_A,
-    ));
+    if model.trail && model.frame_count > 0 {
+        // Leave the previous frame mostly intact so the cube streaks as it rotates.
+        draw.rect().x_y(0.0, 0.0).w_h(WIDTH as f32, HEIGHT as f32).color(srgba(
+            BACKGROUND_COLOR_R as f32 / 255.0,
+            BACKGROUND_COLOR_G as f32 / 255.0,
+            BACKGROUND_COLOR_B as f32 / 255.0,
+            model.trail_decay,
+        ));
+    } else {
+        draw.background().color(srgba(
+            BACKGROUND_COLOR_R,
+            BACKGROUND_COLOR_G,
+            BACKGROUND_COLOR_B,
+            BACKGROUND_COLOR_A,
+        ));
+    }
 
     let cx = 0.0;
     let cy = 0.0;
     let cz = model.z_start + GRID_SIZE / 2.0;
 
     let view_data = ViewData {
-        angle_x: model.angle_x,
-        angle_y: model.angle_y,
+        orientation: model.orientation,
         z_start: model.z_start,
-        colors: Arc::new(generate_colors(GRID_COUNT)),
+        colors: Arc::new(generate_colors(GRID_COUNT, model.color_space)),
+        render_mode: model.render_mode,
+        mandelbulb_power: model.mandelbulb_power,
+        mandelbulb_eps: model.mandelbulb_eps,
+        mandelbulb_iterations: model.mandelbulb_iterations,
     };
 
     let coordinates: Vec<(f32, f32, f32, Srgba)> = (0..GRID_COUNT)
@@ -122,59 +438,91 @@ fn view(app: &App, model: &Model, frame: Frame) {
             let view_data = view_data.clone();
             (0..GRID_COUNT).into_par_iter().flat_map(move |iy| {
                 let view_data = view_data.clone();
-                (0..GRID_COUNT).into_par_iter().map(move |iz| {
+                (0..GRID_COUNT).into_par_iter().filter_map(move |iz| {
+                    let color = match view_data.render_mode {
+                        RenderMode::Lattice => {
+                            let color_idx = ix * GRID_COUNT * GRID_COUNT + iy * GRID_COUNT + iz;
+                            view_data.colors[color_idx]
+                        }
+                        RenderMode::Mandelbulb => {
+                            let p = Vec3::new(
+                                fractal_coord(ix, GRID_COUNT),
+                                fractal_coord(iy, GRID_COUNT),
+                                fractal_coord(iz, GRID_COUNT),
+                            );
+                            let (de, iterations) = mandelbulb_de(
+                                p,
+                                view_data.mandelbulb_power,
+                                view_data.mandelbulb_iterations,
+                            );
+                            if de.abs() >= view_data.mandelbulb_eps {
+                                return None;
+                            }
+                            let t = iterations as f32 / view_data.mandelbulb_iterations as f32;
+                            let (r, g, b) = hsv_to_rgb(t * 360.0, 1.0, 1.0);
+                            srgba(r, g, b, 1.0)
+                        }
+                    };
+
                     let x = (ix as f32) * GRID_PAD - GRID_SIZE / 2.0;
                     let y = (iy as f32) * GRID_PAD - GRID_SIZE / 2.0;
                     let z = view_data.z_start + (iz as f32) * GRID_PAD;
-                    // X-axis rotation
-                    let dy = y - cy;
-                    let dz = z - cz;
-
-                    let a_x = dz.atan2(dy);
-                    let m_x = (dy * dy + dz * dz).sqrt();
-
-                    let dy = (a_x + view_data.angle_x).cos() * m_x;
-                    let dz = (a_x + view_data.angle_x).sin() * m_x;
 
-                    let y = dy + cy;
-                    let z = dz + cz;
+                    let relative = Vec3::new(x - cx, y - cy, z - cz);
+                    let rotated = view_data.orientation * relative;
 
-                    // Y-axis rotation
-                    let dx = x - cx;
-                    let dz = z - cz;
+                    let x = rotated.x + cx;
+                    let y = rotated.y + cy;
+                    let z = rotated.z + cz;
 
-                    let a_y = dz.atan2(dx);
-                    let m_y = (dx * dx + dz * dz).sqrt();
-
-                    let dx = (a_y + view_data.angle_y).cos() * m_y;
-                    let dz = (a_y + view_data.angle_y).sin() * m_y;
-
-                    let x = dx + cx;
-                    let z = dz + cz;
+                    // Points at or behind the near plane would blow up (or invert) under
+                    // the `1/z` perspective divide, so drop them before projecting.
+                    if z <= NEAR_PLANE {
+                        return None;
+                    }
 
                     let x = x / z;
                     let y = y / z;
 
-                    let color_idx = ix * GRID_COUNT * GRID_COUNT + iy * GRID_COUNT + iz;
-                    let color = view_data.colors[color_idx];
-
-                    (x, y, z, color)
+                    Some((x, y, z, color))
                 })
             })
         })
         .collect();
 
-    for (x, y, _z, color) in coordinates {
+    // Painter's algorithm: draw farthest points first so nearer ones occlude them.
+    let mut coordinates = coordinates;
+    coordinates.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal));
+
+    let bg = srgba(
+        BACKGROUND_COLOR_R as f32 / 255.0,
+        BACKGROUND_COLOR_G as f32 / 255.0,
+        BACKGROUND_COLOR_B as f32 / 255.0,
+        BACKGROUND_COLOR_A as f32 / 255.0,
+    );
+
+    for (x, y, z, color) in coordinates {
+        let fog_t = (z - model.fog_near) / (model.fog_far - model.fog_near);
+        let color = lerp_color(color, bg, fog_t);
+        let radius = CIRCLE_RADIUS * model.focal_length / z;
+
         draw.ellipse()
             .x_y(
                 (x + 1.0) / 2.0 * WIDTH as f32 - WIDTH as f32 / 2.0,
                 (y + 1.0) / 2.0 * HEIGHT as f32 - HEIGHT as f32 / 2.0,
             )
-            .radius(CIRCLE_RADIUS)
+            .radius(radius)
             .color(color);
     }
 
     draw.to_frame(app, &frame).unwrap();
+
+    if model.recording {
+        let dir = recording_dir(model.recording_run);
+        let _ = std::fs::create_dir_all(&dir);
+        let path = format!("{}/{:06}.png", dir, model.recording_frame);
+        app.main_window().capture_frame(path);
+    }
 }
 
 fn ui_view(_app: &App, model: &Model, frame: Frame) {
@@ -185,6 +533,42 @@ fn raw_ui_event(_app: &App, model: &mut Model, event: &nannou::winit::event::Win
     model.ui.handle_raw_event(event);
 }
 
+/// Drives the arcball camera from mouse drags on the main window: press-and-drag maps the
+/// cursor onto a virtual trackball sphere and composes the resulting rotation into
+/// `Model::orientation`, while auto-spin keeps accumulating on top in `update`.
+fn raw_main_event(app: &App, model: &mut Model, event: &WindowEvent) {
+    match event {
+        WindowEvent::MouseInput {
+            state,
+            button: MouseButton::Left,
+            ..
+        } => match state {
+            ElementState::Pressed => {
+                let pos = app.mouse.position();
+                let x = pos.x / (WIDTH as f32 / 2.0);
+                let y = pos.y / (HEIGHT as f32 / 2.0);
+                model.drag_start = Some(arcball_point(x, y));
+            }
+            ElementState::Released => {
+                model.drag_start = None;
+            }
+        },
+        WindowEvent::CursorMoved { .. } => {
+            if let Some(start) = model.drag_start {
+                let pos = app.mouse.position();
+                let x = pos.x / (WIDTH as f32 / 2.0);
+                let y = pos.y / (HEIGHT as f32 / 2.0);
+                let current = arcball_point(x, y);
+
+                let delta = arcball_quat(start, current);
+                model.orientation = (delta * model.orientation).normalize();
+                model.drag_start = Some(current);
+            }
+        }
+        _ => {}
+    }
+}
+
 fn update_ui(model: &mut Model) {
     let ctx = model.ui.begin_frame();
     egui::Window::new("Control Panel")
@@ -199,5 +583,40 @@ fn update_ui(model: &mut Model) {
                 egui::Slider::new(&mut model.rot_speed_y, -2.0 * PI..=2.0 * PI)
                     .text("Rotation Speed Y"),
             );
+            ui.add(egui::Slider::new(&mut model.focal_length, 0.1..=3.0).text("Focal Length"));
+            ui.add(egui::Slider::new(&mut model.fog_near, 0.0..=2.0).text("Fog Near"));
+            ui.add(egui::Slider::new(&mut model.fog_far, 0.0..=4.0).text("Fog Far"));
+
+            egui::ComboBox::from_label("Color Space")
+                .selected_text(model.color_space.label())
+                .show_ui(ui, |ui| {
+                    for space in ColorSpace::ALL {
+                        ui.selectable_value(&mut model.color_space, space, space.label());
+                    }
+                });
+
+            egui::ComboBox::from_label("Render Mode")
+                .selected_text(model.render_mode.label())
+                .show_ui(ui, |ui| {
+                    for mode in RenderMode::ALL {
+                        ui.selectable_value(&mut model.render_mode, mode, mode.label());
+                    }
+                });
+
+            if model.render_mode == RenderMode::Mandelbulb {
+                ui.add(egui::Slider::new(&mut model.mandelbulb_power, 1.0..=12.0).text("Power"));
+                ui.add(egui::Slider::new(&mut model.mandelbulb_eps, 0.001..=0.1).text("Epsilon"));
+                ui.add(
+                    egui::Slider::new(&mut model.mandelbulb_iterations, 4..=32)
+                        .text("Iterations"),
+                );
+            }
+
+            ui.checkbox(&mut model.trail, "Motion Trail");
+            if model.trail {
+                ui.add(egui::Slider::new(&mut model.trail_decay, 0.02..=0.2).text("Trail Decay"));
+            }
+
+            ui.checkbox(&mut model.recording, "Record PNG Sequence");
         });
 }